@@ -1,8 +1,13 @@
 use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
 use std::collections::BTreeSet;
 use std::convert::TryInto;
-use std::path::Path;
-use std::sync::{Arc, RwLock};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
+use std::thread;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use wasmtime_provider::wasmtime;
 
@@ -11,7 +16,11 @@ use crate::evaluation_context::EvaluationContext;
 use crate::policy_evaluator::{PolicyEvaluator, PolicyExecutionMode};
 use crate::policy_metadata::ContextAwareResource;
 use crate::runtimes::wapc::evaluation_context_registry::register_policy;
-use crate::runtimes::{rego::BurregoStack, wapc::WapcStack, wasi_cli, Runtime};
+use crate::runtimes::{
+    rego::BurregoStack,
+    wapc::{PrebuiltWapcStack, WapcStack},
+    wasi_cli, Runtime,
+};
 
 /// Configure behavior of wasmtime [epoch-based interruptions](https://docs.rs/wasmtime/latest/wasmtime/struct.Config.html#method.epoch_interruption)
 ///
@@ -29,6 +38,163 @@ pub(crate) struct EpochDeadlines {
     pub wapc_func: u64,
 }
 
+/// Configure behavior of wasmtime [fuel metering](https://docs.rs/wasmtime/latest/wasmtime/struct.Config.html#method.consume_fuel)
+///
+/// Fuel is an alternative to [`EpochDeadlines`]: instead of an embedder-defined
+/// tick granularity driven by wall-clock time, it gives a deterministic,
+/// reproducible instruction budget. The same two kinds of deadlines apply:
+///
+/// * waPC initialization code: this is the code defined by the module inside
+///   of the `wapc_init` or the `_start` functions
+/// * user function: the actual waPC guest function written by an user
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FuelDeadlines {
+    /// Amount of fuel the waPC initialization code can consume
+    pub wapc_init: u64,
+
+    /// Amount of fuel any regular waPC guest function call can consume
+    pub wapc_func: u64,
+}
+
+/// Background thread that increments a `wasmtime::Engine`'s epoch counter at
+/// a fixed cadence, used by
+/// [`PolicyEvaluatorBuilder::enable_epoch_interruptions_with_durations`] so
+/// callers can express deadlines in wall-clock durations instead of opaque
+/// tick counts.
+///
+/// One `EpochTicker` is shared by every policy built against the same
+/// engine (see [`epoch_ticker_for`]); the ticker thread is told to stop when
+/// the last `Arc<EpochTicker>` referencing it is dropped, and exits once its
+/// current tick is over.
+pub(crate) struct EpochTicker {
+    stop: Arc<AtomicBool>,
+}
+
+impl EpochTicker {
+    fn spawn(engine: wasmtime::Engine, tick: Duration) -> EpochTicker {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_thread = stop.clone();
+
+        thread::spawn(move || {
+            while !stop_thread.load(Ordering::Relaxed) {
+                thread::sleep(tick);
+                engine.increment_epoch();
+            }
+        });
+
+        EpochTicker { stop }
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        // the ticker thread checks this flag once per tick, after its
+        // `tick`-long sleep and epoch increment, and exits on its own; no
+        // need to join it here
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+static EPOCH_TICKERS: OnceLock<Mutex<Vec<(wasmtime::Engine, Duration, Weak<EpochTicker>)>>> =
+    OnceLock::new();
+
+/// Return the `EpochTicker` already running for `engine`, or spawn a new one
+/// ticking every `tick` and register it for reuse by other policies that
+/// share the same engine.
+///
+/// Fails if `engine` already has a ticker registered with a different
+/// `tick`: a single engine only has one epoch counter, so two different
+/// cadences can't both be honored by the same running ticker. In practice
+/// this shouldn't happen, since [`owned_engine_for`] only ever hands out the
+/// same engine instance for a given `tick`, but the check is kept as a
+/// defensive guard against a mismatched deadline being computed against a
+/// cadence the running ticker doesn't use.
+fn epoch_ticker_for(engine: &wasmtime::Engine, tick: Duration) -> Result<Arc<EpochTicker>> {
+    let registry = EPOCH_TICKERS.get_or_init(|| Mutex::new(Vec::new()));
+    let mut entries = registry.lock().unwrap();
+    entries.retain(|(_, _, ticker)| ticker.strong_count() > 0);
+
+    if let Some((_, registered_tick, ticker)) = entries
+        .iter()
+        .find(|(e, _, _)| wasmtime::Engine::same(e, engine))
+    {
+        if let Some(ticker) = ticker.upgrade() {
+            if *registered_tick != tick {
+                return Err(anyhow!(
+                    "engine already has an epoch ticker running with a tick of {:?}, cannot also request a tick of {:?}",
+                    registered_tick,
+                    tick
+                ));
+            }
+            return Ok(ticker);
+        }
+    }
+
+    let ticker = Arc::new(EpochTicker::spawn(engine.clone(), tick));
+    entries.push((engine.clone(), tick, Arc::downgrade(&ticker)));
+    Ok(ticker)
+}
+
+/// Fingerprint of the `wasmtime::Config` bits that determine whether two
+/// builder-owned engines created for
+/// [`PolicyEvaluatorBuilder::enable_epoch_interruptions_with_durations`] can
+/// be the very same `wasmtime::Engine` instance.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct EngineRecipe {
+    wasmtime_cache: bool,
+    async_support: bool,
+    tick: Duration,
+}
+
+#[allow(clippy::type_complexity)]
+static OWNED_ENGINES: OnceLock<Mutex<Vec<(EngineRecipe, wasmtime::Engine)>>> = OnceLock::new();
+
+/// Return the builder-owned `wasmtime::Engine` matching `recipe`, creating
+/// and registering a new one on first use.
+///
+/// This is what makes the ticker sharing promised by
+/// [`PolicyEvaluatorBuilder::enable_epoch_interruptions_with_durations`]
+/// possible: [`epoch_ticker_for`] can only reuse a ticker across policies
+/// that end up with the exact same `wasmtime::Engine` instance, and the
+/// builder otherwise has no way to hand out the same engine to two separate
+/// `PolicyEvaluatorBuilder`s. Unlike [`EPOCH_TICKERS`], entries here are
+/// never evicted: engines are cheap to clone and keeping one alive per
+/// distinct `EngineRecipe` for the life of the process is what lets
+/// independently-built policies that request the same recipe land on the
+/// same engine.
+fn owned_engine_for(recipe: EngineRecipe) -> Result<wasmtime::Engine> {
+    let registry = OWNED_ENGINES.get_or_init(|| Mutex::new(Vec::new()));
+    let mut entries = registry.lock().unwrap();
+
+    if let Some((_, engine)) = entries.iter().find(|(r, _)| *r == recipe) {
+        return Ok(engine.clone());
+    }
+
+    let mut wasmtime_config = wasmtime::Config::new();
+    if recipe.wasmtime_cache {
+        wasmtime_config.cache_config_load_default()?;
+    }
+    wasmtime_config.epoch_interruption(true);
+    if recipe.async_support {
+        wasmtime_config.async_support(true);
+    }
+
+    let engine = wasmtime::Engine::new(&wasmtime_config)
+        .map_err(|e| anyhow!("cannot create wasmtime engine: {:?}", e))?;
+    entries.push((recipe, engine.clone()));
+    Ok(engine)
+}
+
+/// Convert a wall-clock `deadline` into the number of `tick`-sized epoch
+/// increments it corresponds to, rounding up and never returning zero (a
+/// zero-tick deadline would fire immediately, regardless of how small
+/// `deadline` is).
+fn ticks_for_duration(deadline: Duration, tick: Duration) -> u64 {
+    let ticks = deadline.as_nanos().div_ceil(tick.as_nanos().max(1));
+    u64::try_from(ticks).unwrap_or(u64::MAX).max(1)
+}
+
 /// Helper Struct that creates a `PolicyEvaluator` object
 #[derive(Default)]
 pub struct PolicyEvaluatorBuilder {
@@ -42,7 +208,11 @@ pub struct PolicyEvaluatorBuilder {
     callback_channel: Option<mpsc::Sender<CallbackRequest>>,
     wasmtime_cache: bool,
     epoch_deadlines: Option<EpochDeadlines>,
+    fuel_deadlines: Option<FuelDeadlines>,
     ctx_aware_resources_allow_list: BTreeSet<ContextAwareResource>,
+    precompiled_artifact_cache: Option<PathBuf>,
+    memory_snapshotting: bool,
+    epoch_tick_durations: Option<(Duration, Duration, Duration)>,
 }
 
 impl PolicyEvaluatorBuilder {
@@ -109,6 +279,43 @@ impl PolicyEvaluatorBuilder {
         self
     }
 
+    /// Enable a durable, on-disk cache of AOT-compiled module artifacts.
+    ///
+    /// When set, `build` computes a cache key from the SHA-256 of the Wasm
+    /// bytes and a fingerprint of the engine configuration, and looks for
+    /// `<dir>/<key>.cwasm`. If the artifact is found, it's loaded via
+    /// [`wasmtime::Module::deserialize_file`] instead of being recompiled
+    /// through Cranelift. On a cache miss (or on a deserialization error,
+    /// which can happen when the artifact was produced by an incompatible
+    /// engine), the module is compiled normally and the serialized artifact
+    /// is written back to the cache.
+    ///
+    /// This is unrelated to [`PolicyEvaluatorBuilder::enable_wasmtime_cache`],
+    /// which only configures wasmtime's own in-process compilation cache.
+    pub fn precompiled_artifact_cache(mut self, dir: &Path) -> PolicyEvaluatorBuilder {
+        self.precompiled_artifact_cache = Some(dir.to_owned());
+        self
+    }
+
+    /// Enable memory snapshotting for the waPC runtime.
+    ///
+    /// Normally every evaluation re-instantiates the module and its linear
+    /// memory from scratch. When enabled, right after the first evaluation
+    /// completes `wapc_init`/`_start`, the guest's linear memory (bytes,
+    /// size, and mutable globals) is captured once. Every subsequent
+    /// evaluation restores that snapshot into the existing instance instead
+    /// of re-instantiating, which gives the guest a clean, deterministic
+    /// starting state at a fraction of the cost.
+    ///
+    /// Only applicable to non-shared memories that don't grow past the
+    /// snapshot: the runtime falls back to full re-instantiation whenever
+    /// the memory type doesn't support it, or if restoring the snapshot
+    /// fails for any reason.
+    pub fn enable_memory_snapshotting(mut self) -> PolicyEvaluatorBuilder {
+        self.memory_snapshotting = true;
+        self
+    }
+
     /// Set the list of Kubernetes resources the policy can have access to
     pub fn context_aware_resources_allowed(
         mut self,
@@ -150,6 +357,70 @@ impl PolicyEvaluatorBuilder {
         self
     }
 
+    /// Enable Wasmtime epoch-based interruptions, like
+    /// [`PolicyEvaluatorBuilder::enable_epoch_interruptions`], but expressed as
+    /// wall-clock durations instead of raw tick counts, with the ticker managed
+    /// by the builder itself.
+    ///
+    /// `enable_epoch_interruptions` requires the caller to spawn their own
+    /// thread calling `engine.increment_epoch()` and to decide what a single
+    /// tick means; forgetting the ticker is a common way for deadlines to
+    /// silently never fire. This variant spawns (or reuses, when several
+    /// policies are built with the same cache setting, async support and
+    /// `tick`) a single background ticker that increments the epoch every
+    /// `tick`, and converts `wapc_init`/`wapc_func` into the equivalent
+    /// number of ticks.
+    ///
+    /// Only usable when the builder creates its own `wasmtime::Engine`:
+    /// combining this with an explicit `engine` (via
+    /// [`PolicyEvaluatorBuilder::engine`]) is rejected by `validate_user_input`,
+    /// since the builder can only manage the ticker's lifecycle for an engine
+    /// it owns. To make sharing actually reachable across independently
+    /// built policies, the builder-owned engine itself is looked up via
+    /// [`owned_engine_for`] rather than created fresh on every `build`: two
+    /// builders that request the same recipe end up with the very same
+    /// `wasmtime::Engine` instance, and therefore the very same ticker. The
+    /// ticker thread is told to stop when the last evaluator referencing it
+    /// is dropped, and exits once its current tick is over.
+    #[must_use]
+    pub fn enable_epoch_interruptions_with_durations(
+        mut self,
+        wapc_init: Duration,
+        wapc_func: Duration,
+        tick: Duration,
+    ) -> Self {
+        self.epoch_tick_durations = Some((wapc_init, wapc_func, tick));
+        self
+    }
+
+    /// Enable Wasmtime [fuel metering](wasmtime::Config::consume_fuel) and set the fuel
+    /// budgets to be enforced
+    ///
+    /// Two kind of budgets have to be set:
+    ///
+    /// * `wapc_init_fuel`: the amount of fuel the waPC initialization code is allowed to
+    ///   consume before the code is interrupted. This is the code usually defined inside
+    ///   of the `wapc_init`/`_start` functions
+    /// * `wapc_func_fuel`: the amount of fuel any regular waPC guest function call is
+    ///   allowed to consume before its terminated by the host
+    ///
+    /// Fuel metering is an alternative to [`PolicyEvaluatorBuilder::enable_epoch_interruptions`]:
+    /// it gives a deterministic, reproducible instruction budget instead of a wall-clock
+    /// driven tick count. The two are mutually exclusive, because they configure the
+    /// wasmtime engine differently.
+    ///
+    /// **Warning:** when providing an instance of `wasmtime::Engine` via the
+    /// `WasmtimeEngineProvider::engine` helper, ensure the `wasmtime::Engine`
+    /// has been created with the `consume_fuel` feature enabled
+    #[must_use]
+    pub fn enable_fuel_metering(mut self, wapc_init_fuel: u64, wapc_func_fuel: u64) -> Self {
+        self.fuel_deadlines = Some(FuelDeadlines {
+            wapc_init: wapc_init_fuel,
+            wapc_func: wapc_func_fuel,
+        });
+        self
+    }
+
     /// Specify the channel that is used by the synchronous world (the waPC `host_callback`
     /// function) to obtain information that can be computed only from within a
     /// tokio runtime.
@@ -203,43 +474,147 @@ impl PolicyEvaluatorBuilder {
             return Err(anyhow!("Must specify execution mode"));
         }
 
+        if self.epoch_deadlines.is_some() && self.fuel_deadlines.is_some() {
+            return Err(anyhow!(
+                "Cannot specify both epoch interruptions and fuel metering: they configure the wasmtime engine differently"
+            ));
+        }
+
+        if self.epoch_deadlines.is_some() && self.epoch_tick_durations.is_some() {
+            return Err(anyhow!(
+                "Cannot specify both 'enable_epoch_interruptions' and 'enable_epoch_interruptions_with_durations'"
+            ));
+        }
+
+        if self.fuel_deadlines.is_some() && self.epoch_tick_durations.is_some() {
+            return Err(anyhow!(
+                "Cannot specify both fuel metering and duration-based epoch interruptions: they configure the wasmtime engine differently"
+            ));
+        }
+
+        if self.engine.is_some() && self.epoch_tick_durations.is_some() {
+            return Err(anyhow!(
+                "Cannot use 'enable_epoch_interruptions_with_durations' together with an explicit 'engine': the builder only manages the ticker for engines it creates itself"
+            ));
+        }
+
+        if let Some((wapc_init, wapc_func, tick)) = self.epoch_tick_durations {
+            if tick.is_zero() {
+                return Err(anyhow!(
+                    "'enable_epoch_interruptions_with_durations' tick must be greater than zero: a zero tick would busy-spin the ticker thread and flood the engine's epoch counter"
+                ));
+            }
+            if tick > wapc_init || tick > wapc_func {
+                return Err(anyhow!(
+                    "'enable_epoch_interruptions_with_durations' tick must not be greater than either deadline: a coarser tick can only make a deadline fire later than requested, never on time"
+                ));
+            }
+        }
+
         Ok(())
     }
 
-    /// Create the instance of `PolicyEvaluator` to be used
-    pub fn build(&self) -> Result<PolicyEvaluator> {
-        self.validate_user_input()?;
+    /// Build the `wasmtime::Engine` and `wasmtime::Module` shared by all the
+    /// execution modes, honoring the cache/epoch/fuel/async settings
+    /// configured on this builder.
+    ///
+    /// Also returns the effective [`EpochDeadlines`] to use (which, when
+    /// [`PolicyEvaluatorBuilder::enable_epoch_interruptions_with_durations`]
+    /// was used, are derived from wall-clock durations rather than taken
+    /// verbatim from `self.epoch_deadlines`), together with the
+    /// [`EpochTicker`] driving them, if any. The caller must keep the
+    /// returned ticker alive for as long as the resulting evaluator is
+    /// going to be used.
+    ///
+    /// When [`PolicyEvaluatorBuilder::enable_epoch_interruptions_with_durations`]
+    /// is used, the engine itself comes from [`owned_engine_for`] rather
+    /// than being created fresh: that's what lets two builders configured
+    /// with the same recipe (cache setting, async support, tick) end up
+    /// sharing both the engine and, in turn, its [`EpochTicker`].
+    #[allow(clippy::type_complexity)]
+    fn build_engine_and_module(
+        &self,
+        async_support: bool,
+    ) -> Result<(
+        wasmtime::Engine,
+        wasmtime::Module,
+        Option<EpochDeadlines>,
+        Option<Arc<EpochTicker>>,
+    )> {
+        let owns_engine = self.engine.is_none();
+
+        let engine = if let Some(e) = &self.engine {
+            e.clone()
+        } else if let Some((_, _, tick)) = self.epoch_tick_durations {
+            owned_engine_for(EngineRecipe {
+                wasmtime_cache: self.wasmtime_cache,
+                async_support,
+                tick,
+            })?
+        } else {
+            let mut wasmtime_config = wasmtime::Config::new();
+            if self.wasmtime_cache {
+                wasmtime_config.cache_config_load_default()?;
+            }
+            if self.epoch_deadlines.is_some() {
+                wasmtime_config.epoch_interruption(true);
+            }
+            if self.fuel_deadlines.is_some() {
+                wasmtime_config.consume_fuel(true);
+            }
+            if async_support {
+                wasmtime_config.async_support(true);
+            }
 
-        let engine = self
-            .engine
-            .as_ref()
-            .map_or_else(
-                || {
-                    let mut wasmtime_config = wasmtime::Config::new();
-                    if self.wasmtime_cache {
-                        wasmtime_config.cache_config_load_default()?;
-                    }
-                    if self.epoch_deadlines.is_some() {
-                        wasmtime_config.epoch_interruption(true);
-                    }
-
-                    wasmtime::Engine::new(&wasmtime_config)
-                },
-                |e| Ok(e.clone()),
-            )
-            .map_err(|e| anyhow!("cannot create wasmtime engine: {:?}", e))?;
+            wasmtime::Engine::new(&wasmtime_config)
+                .map_err(|e| anyhow!("cannot create wasmtime engine: {:?}", e))?
+        };
+
+        let (epoch_deadlines, epoch_ticker) = match self.epoch_tick_durations {
+            Some((wapc_init, wapc_func, tick)) if owns_engine => {
+                let ticker = epoch_ticker_for(&engine, tick)?;
+                let deadlines = EpochDeadlines {
+                    wapc_init: ticks_for_duration(wapc_init, tick),
+                    wapc_func: ticks_for_duration(wapc_func, tick),
+                };
+                (Some(deadlines), Some(ticker))
+            }
+            _ => (self.epoch_deadlines, None),
+        };
 
         let module: wasmtime::Module = if let Some(m) = &self.policy_module {
             // it's fine to clone a Module, this is a cheap operation that just
             // copies its internal reference. See wasmtime docs
             m.clone()
         } else {
-            match &self.policy_file {
-                Some(file) => wasmtime::Module::from_file(&engine, file),
-                None => wasmtime::Module::new(&engine, self.policy_contents.as_ref().unwrap()),
-            }?
+            let wasm_bytes = match &self.policy_file {
+                Some(file) => fs::read(file)?,
+                None => self.policy_contents.as_ref().unwrap().clone(),
+            };
+
+            match &self.precompiled_artifact_cache {
+                Some(cache_dir) => load_or_compile_module(
+                    &engine,
+                    cache_dir,
+                    &wasm_bytes,
+                    epoch_deadlines,
+                    self.fuel_deadlines,
+                    async_support,
+                )?,
+                None => wasmtime::Module::new(&engine, &wasm_bytes)?,
+            }
         };
 
+        Ok((engine, module, epoch_deadlines, epoch_ticker))
+    }
+
+    /// Create the instance of `PolicyEvaluator` to be used
+    pub fn build(&self) -> Result<PolicyEvaluator> {
+        self.validate_user_input()?;
+
+        let (engine, module, epoch_deadlines, epoch_ticker) =
+            self.build_engine_and_module(false)?;
+
         let execution_mode = self.execution_mode.unwrap();
 
         let runtime = match execution_mode {
@@ -248,23 +623,41 @@ impl PolicyEvaluatorBuilder {
                 self.worker_id,
                 engine,
                 module,
-                self.epoch_deadlines,
+                epoch_deadlines,
+                self.fuel_deadlines,
+                self.memory_snapshotting,
+                epoch_ticker,
                 self.callback_channel.clone(),
                 &self.ctx_aware_resources_allow_list,
             )?,
             PolicyExecutionMode::Wasi => {
-                let cli_stack = wasi_cli::Stack::new(engine, module, self.epoch_deadlines)?;
+                let cli_stack = wasi_cli::Stack::new(
+                    engine,
+                    module,
+                    epoch_deadlines,
+                    self.fuel_deadlines,
+                    epoch_ticker,
+                )?;
                 Runtime::Cli(cli_stack)
             }
             PolicyExecutionMode::Opa | PolicyExecutionMode::OpaGatekeeper => {
+                if epoch_ticker.is_some() {
+                    return Err(anyhow!(
+                        "enable_epoch_interruptions_with_durations is not supported for the Opa/OpaGatekeeper execution modes"
+                    ));
+                }
+
                 let mut builder = burrego::EvaluatorBuilder::default()
                     .engine(&engine)
                     .module(module)
                     .host_callbacks(crate::runtimes::rego::new_host_callbacks());
 
-                if let Some(deadlines) = self.epoch_deadlines {
+                if let Some(deadlines) = epoch_deadlines {
                     builder = builder.enable_epoch_interruptions(deadlines.wapc_func);
                 }
+                if let Some(fuel) = self.fuel_deadlines {
+                    builder = builder.enable_fuel_metering(fuel.wapc_func);
+                }
                 let evaluator = builder.build()?;
 
                 Runtime::Burrego(BurregoStack {
@@ -281,6 +674,305 @@ impl PolicyEvaluatorBuilder {
             runtime,
         ))
     }
+
+    /// Create the instance of `PolicyEvaluator` to be used, configuring the
+    /// wasmtime engine for asynchronous execution.
+    ///
+    /// With the synchronous API, an epoch deadline can only interrupt the
+    /// guest at a few well-defined checkpoints, and a `host_callback` that
+    /// blocks (e.g. on a slow Kubernetes context-aware lookup) cannot be
+    /// interrupted at all. The async path configures the engine with
+    /// [`wasmtime::Config::async_support`] and drives the guest through
+    /// `call_async`/`instantiate_async`, combined with
+    /// [`wasmtime::Store::epoch_deadline_async_yield_and_update`] so that an
+    /// epoch deadline yields control back to the executor at an `await`
+    /// point instead of hard-trapping. This lets a blocked or long-running
+    /// evaluation be cancelled cleanly by dropping the future.
+    ///
+    /// The synchronous [`PolicyEvaluatorBuilder::build`] is left untouched
+    /// for existing callers.
+    pub async fn build_async(&self) -> Result<PolicyEvaluator> {
+        self.validate_user_input()?;
+
+        let (engine, module, epoch_deadlines, epoch_ticker) = self.build_engine_and_module(true)?;
+
+        let execution_mode = self.execution_mode.unwrap();
+
+        let runtime = match execution_mode {
+            PolicyExecutionMode::KubewardenWapc => {
+                create_wapc_runtime_async(
+                    &self.policy_id,
+                    self.worker_id,
+                    engine,
+                    module,
+                    epoch_deadlines,
+                    self.fuel_deadlines,
+                    self.memory_snapshotting,
+                    epoch_ticker,
+                    self.callback_channel.clone(),
+                    &self.ctx_aware_resources_allow_list,
+                )
+                .await?
+            }
+            PolicyExecutionMode::Wasi => {
+                let cli_stack = wasi_cli::Stack::new_async(
+                    engine,
+                    module,
+                    epoch_deadlines,
+                    self.fuel_deadlines,
+                    epoch_ticker,
+                )
+                .await?;
+                Runtime::Cli(cli_stack)
+            }
+            PolicyExecutionMode::Opa | PolicyExecutionMode::OpaGatekeeper => {
+                return Err(anyhow!(
+                    "Async evaluation is not supported for the Opa/OpaGatekeeper execution modes"
+                ));
+            }
+        };
+
+        Ok(PolicyEvaluator::new(
+            &self.policy_id,
+            self.worker_id,
+            runtime,
+        ))
+    }
+
+    /// Link and pre-instantiate the policy once, returning a cheaply-clonable
+    /// handle that can later be turned into a `PolicyEvaluator` via
+    /// [`PolicyEvaluatorBuilder::build_from_prebuilt`].
+    ///
+    /// `build` re-links the module's imports into a fresh
+    /// `wasmtime::Linker` on every call, which is wasted work when the same
+    /// policy is going to be evaluated by many worker threads. `prebuilt`
+    /// performs the linking and [`wasmtime::Linker::instantiate_pre`] step a
+    /// single time, so `policy-server` can build a policy once and hand a
+    /// cloned `PrebuiltPolicy` to every worker, each of which only pays for
+    /// `instantiate` afterwards.
+    ///
+    /// Only supported for the `KubewardenWapc` execution mode, which is the
+    /// mode policy-server uses to evaluate the same policy thousands of
+    /// times across its worker pool.
+    pub fn prebuilt(&self) -> Result<PrebuiltPolicy> {
+        self.validate_user_input()?;
+
+        if self.execution_mode != Some(PolicyExecutionMode::KubewardenWapc) {
+            return Err(anyhow!(
+                "`prebuilt` is only supported for the KubewardenWapc execution mode"
+            ));
+        }
+
+        if self.epoch_tick_durations.is_some() {
+            return Err(anyhow!(
+                "enable_epoch_interruptions_with_durations is not supported together with `prebuilt`"
+            ));
+        }
+
+        let (engine, module, _, _) = self.build_engine_and_module(false)?;
+        let wapc_stack = WapcStack::instantiate_pre(engine, module, self.memory_snapshotting)?;
+
+        Ok(PrebuiltPolicy {
+            wapc_stack,
+            epoch_interruption_enabled: self.epoch_deadlines.is_some(),
+            fuel_metering_enabled: self.fuel_deadlines.is_some(),
+        })
+    }
+
+    /// Build a `PolicyEvaluator` out of a policy that was already linked and
+    /// pre-instantiated once via [`PolicyEvaluatorBuilder::prebuilt`], only
+    /// paying for `instantiate` instead of relinking the module's imports.
+    ///
+    /// Like `prebuilt`, this doesn't support
+    /// [`PolicyEvaluatorBuilder::enable_epoch_interruptions_with_durations`]:
+    /// the engine that was linked is whatever `prebuilt` built against, so
+    /// there's no builder-owned engine left here for a ticker to be spawned
+    /// on.
+    ///
+    /// **Warning:** `epoch_deadlines`/`fuel_deadlines` are enforced by
+    /// calling `set_epoch_deadline`/`set_fuel` at `instantiate` time, but
+    /// whether the engine actually has `epoch_interruption`/`consume_fuel`
+    /// enabled was frozen when `prebuilt()` built it. This builder must
+    /// therefore enable the same metering as the one that produced
+    /// `prebuilt`, otherwise the deadlines/fuel budget set here would be
+    /// silently unenforced; that mismatch is rejected below instead.
+    pub fn build_from_prebuilt(&self, prebuilt: &PrebuiltPolicy) -> Result<PolicyEvaluator> {
+        self.validate_user_input()?;
+
+        if self.epoch_tick_durations.is_some() {
+            return Err(anyhow!(
+                "enable_epoch_interruptions_with_durations is not supported together with `prebuilt`"
+            ));
+        }
+
+        if self.epoch_deadlines.is_some() != prebuilt.epoch_interruption_enabled {
+            return Err(anyhow!(
+                "epoch interruption must be enabled (or not) on this builder exactly as it was on the builder that produced `prebuilt`: the engine's `epoch_interruption` setting was frozen at `prebuilt()` time"
+            ));
+        }
+
+        if self.fuel_deadlines.is_some() != prebuilt.fuel_metering_enabled {
+            return Err(anyhow!(
+                "fuel metering must be enabled (or not) on this builder exactly as it was on the builder that produced `prebuilt`: the engine's `consume_fuel` setting was frozen at `prebuilt()` time"
+            ));
+        }
+
+        let wapc_stack = prebuilt
+            .wapc_stack
+            .instantiate(self.epoch_deadlines, self.fuel_deadlines)?;
+        let eval_ctx = Arc::new(RwLock::new(EvaluationContext {
+            policy_id: self.policy_id.clone(),
+            callback_channel: self.callback_channel.clone(),
+            ctx_aware_resources_allow_list: self.ctx_aware_resources_allow_list.clone(),
+        }));
+        register_policy(wapc_stack.wapc_host_id(), self.worker_id, eval_ctx);
+
+        Ok(PolicyEvaluator::new(
+            &self.policy_id,
+            self.worker_id,
+            Runtime::Wapc(wapc_stack),
+        ))
+    }
+}
+
+/// A policy that has been linked and pre-instantiated once via
+/// [`wasmtime::Linker::instantiate_pre`], produced by
+/// [`PolicyEvaluatorBuilder::prebuilt`]. Cheap to clone: cloning only bumps a
+/// reference count to the shared `wasmtime::InstancePre`.
+#[derive(Clone)]
+pub struct PrebuiltPolicy {
+    wapc_stack: PrebuiltWapcStack,
+
+    /// Whether the engine `wapc_stack` was built against has
+    /// `epoch_interruption`/`consume_fuel` enabled. These are frozen into
+    /// the engine at `prebuilt()` time, so
+    /// [`PolicyEvaluatorBuilder::build_from_prebuilt`] must be called with a
+    /// builder that agrees on both: passing `epoch_deadlines`/
+    /// `fuel_deadlines` the engine doesn't have the matching feature for
+    /// would have `instantiate`'s `set_epoch_deadline`/`set_fuel` silently
+    /// do nothing.
+    epoch_interruption_enabled: bool,
+    fuel_metering_enabled: bool,
+}
+
+/// Load a [`wasmtime::Module`] from `cache_dir` if a compatible artifact has
+/// already been compiled for `wasm_bytes`, otherwise compile it through
+/// Cranelift and persist the serialized artifact for next time.
+///
+/// The cache key is derived from the SHA-256 of the Wasm bytes combined with
+/// a fingerprint of the engine configuration that affects the shape of the
+/// compiled artifact. This is required because `Module::deserialize_file` is
+/// only safe to call with an artifact produced by a compatible engine: a
+/// stale or foreign artifact must never be loaded. The key covers the
+/// wasmtime version and the engine settings this builder varies; target
+/// specific details are left to `deserialize_file`'s own compatibility check
+/// (see [`precompiled_artifact_cache_key`]).
+fn load_or_compile_module(
+    engine: &wasmtime::Engine,
+    cache_dir: &Path,
+    wasm_bytes: &[u8],
+    epoch_deadlines: Option<EpochDeadlines>,
+    fuel_deadlines: Option<FuelDeadlines>,
+    async_support: bool,
+) -> Result<wasmtime::Module> {
+    let cache_path = cache_dir.join(format!(
+        "{}.cwasm",
+        precompiled_artifact_cache_key(wasm_bytes, epoch_deadlines, fuel_deadlines, async_support)
+    ));
+
+    if cache_path.exists() {
+        // SAFETY: the cache key folds in the wasmtime version and the engine
+        // configuration bits this builder varies, and `deserialize_file`
+        // refuses artifacts whose embedded header doesn't match `engine`
+        // (e.g. one built for other target features). Any artifact that
+        // fails to deserialize (e.g. truncated by a crash) is treated as a
+        // miss below.
+        if let Ok(module) = unsafe { wasmtime::Module::deserialize_file(engine, &cache_path) } {
+            return Ok(module);
+        }
+    }
+
+    let module = wasmtime::Module::new(engine, wasm_bytes)?;
+    if let Err(e) = persist_compiled_module(&module, cache_dir, &cache_path) {
+        // a cache write failure must not fail the evaluation: we already
+        // have a perfectly usable, freshly compiled module in hand
+        tracing::warn!(error = ?e, path = ?cache_path, "cannot persist precompiled artifact cache entry");
+    }
+
+    Ok(module)
+}
+
+/// Compute the cache key used by [`load_or_compile_module`].
+///
+/// Folds in every `wasmtime::Config` bit that `build_engine_and_module` sets
+/// based on the builder's state and that affects the shape of the compiled
+/// artifact: epoch interruption, fuel metering, and async support. Missing
+/// any of these would let two builds that configure the engine differently
+/// collide on the same cache entry.
+///
+/// Deliberately does *not* fold in the target ISA, enabled CPU features, or
+/// other Cranelift codegen flags: correctness there is not this function's
+/// job. It's delegated to `Module::deserialize_file` itself, which embeds a
+/// header describing the engine that produced the artifact and refuses to
+/// deserialize one it doesn't recognize as compatible. `load_or_compile_module`
+/// treats that refusal as a cache miss and recompiles. So a cache dir shared
+/// across hosts with different target features is safe, not because the key
+/// accounts for them, but because a foreign-target artifact never makes it
+/// past `deserialize_file`.
+///
+/// Also deliberately does *not* fold in the execution mode, even though it's
+/// another axis the builder varies on: unlike epoch/fuel/async, it's not a
+/// `wasmtime::Config` bit and it doesn't change how `Module::new` compiles
+/// `wasm_bytes`. A `KubewardenWapc` build and an `Opa` build of the same
+/// bytes with the same epoch/fuel/async settings produce byte-identical
+/// `.cwasm` artifacts, so keying on execution mode too would only fragment
+/// the cache without buying any extra safety.
+fn precompiled_artifact_cache_key(
+    wasm_bytes: &[u8],
+    epoch_deadlines: Option<EpochDeadlines>,
+    fuel_deadlines: Option<FuelDeadlines>,
+    async_support: bool,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(wasm_bytes);
+    hasher.update(wasmtime::VERSION.as_bytes());
+    hasher.update([
+        u8::from(epoch_deadlines.is_some()),
+        u8::from(fuel_deadlines.is_some()),
+        u8::from(async_support),
+    ]);
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Serialize `module` and write it to `cache_path` atomically, so that a
+/// concurrent reader never observes a partially written artifact.
+fn persist_compiled_module(
+    module: &wasmtime::Module,
+    cache_dir: &Path,
+    cache_path: &Path,
+) -> Result<()> {
+    fs::create_dir_all(cache_dir)?;
+
+    let serialized = module.serialize()?;
+    // The cache key is deterministic, so two concurrent writers compiling
+    // the same not-yet-cached policy (e.g. several policy-server workers
+    // starting up at once) would otherwise race on the same tmp path. Give
+    // every write its own tmp file, named after the process, thread, and a
+    // monotonically increasing per-process counter.
+    static WRITER_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let writer_id = WRITER_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let tmp_path = cache_path.with_extension(format!(
+        "{}.{:?}.{}.cwasm.tmp",
+        std::process::id(),
+        thread::current().id(),
+        writer_id,
+    ));
+
+    fs::write(&tmp_path, serialized)?;
+    fs::rename(&tmp_path, cache_path)?;
+
+    Ok(())
 }
 
 fn create_wapc_runtime(
@@ -289,10 +981,53 @@ fn create_wapc_runtime(
     engine: wasmtime::Engine,
     module: wasmtime::Module,
     epoch_deadlines: Option<EpochDeadlines>,
+    fuel_deadlines: Option<FuelDeadlines>,
+    memory_snapshotting: bool,
+    epoch_ticker: Option<Arc<EpochTicker>>,
     callback_channel: Option<mpsc::Sender<CallbackRequest>>,
     ctx_aware_resources_allow_list: &BTreeSet<ContextAwareResource>,
 ) -> Result<Runtime> {
-    let wapc_stack = WapcStack::new(engine, module, epoch_deadlines)?;
+    let wapc_stack = WapcStack::new(
+        engine,
+        module,
+        epoch_deadlines,
+        fuel_deadlines,
+        memory_snapshotting,
+        epoch_ticker,
+    )?;
+    let eval_ctx = Arc::new(RwLock::new(EvaluationContext {
+        policy_id: policy_id.to_owned(),
+        callback_channel,
+        ctx_aware_resources_allow_list: ctx_aware_resources_allow_list.clone(),
+    }));
+    register_policy(wapc_stack.wapc_host_id(), worker_id, eval_ctx);
+
+    Ok(Runtime::Wapc(wapc_stack))
+}
+
+/// Async counterpart of [`create_wapc_runtime`], used by
+/// [`PolicyEvaluatorBuilder::build_async`].
+async fn create_wapc_runtime_async(
+    policy_id: &str,
+    worker_id: u64,
+    engine: wasmtime::Engine,
+    module: wasmtime::Module,
+    epoch_deadlines: Option<EpochDeadlines>,
+    fuel_deadlines: Option<FuelDeadlines>,
+    memory_snapshotting: bool,
+    epoch_ticker: Option<Arc<EpochTicker>>,
+    callback_channel: Option<mpsc::Sender<CallbackRequest>>,
+    ctx_aware_resources_allow_list: &BTreeSet<ContextAwareResource>,
+) -> Result<Runtime> {
+    let wapc_stack = WapcStack::new_async(
+        engine,
+        module,
+        epoch_deadlines,
+        fuel_deadlines,
+        memory_snapshotting,
+        epoch_ticker,
+    )
+    .await?;
     let eval_ctx = Arc::new(RwLock::new(EvaluationContext {
         policy_id: policy_id.to_owned(),
         callback_channel,
@@ -321,6 +1056,9 @@ mod tests {
         let module = wasmtime::Module::new(&engine, wat).expect("cannot compile WAT to wasm");
 
         let epoch_deadlines = None;
+        let fuel_deadlines = None;
+        let memory_snapshotting = false;
+        let epoch_ticker = None;
         let callback_channel = None;
         let ctx_aware_resources_allow_list: BTreeSet<ContextAwareResource> = BTreeSet::new();
 
@@ -330,6 +1068,9 @@ mod tests {
             engine,
             module,
             epoch_deadlines,
+            fuel_deadlines,
+            memory_snapshotting,
+            epoch_ticker,
             callback_channel,
             &ctx_aware_resources_allow_list,
         )
@@ -349,6 +1090,173 @@ mod tests {
         assert_eq!(eval_ctx.policy_id, policy_id);
     }
 
+    #[test]
+    fn ticks_for_duration_rounds_up() {
+        // 2.5 ticks worth of deadline must round up to 3, not truncate to 2
+        assert_eq!(
+            ticks_for_duration(Duration::from_millis(250), Duration::from_millis(100)),
+            3
+        );
+        // exact multiples don't need rounding
+        assert_eq!(
+            ticks_for_duration(Duration::from_millis(300), Duration::from_millis(100)),
+            3
+        );
+    }
+
+    #[test]
+    fn ticks_for_duration_never_returns_zero() {
+        // a deadline shorter than a single tick must still be at least 1 tick,
+        // otherwise it would fire immediately regardless of the deadline
+        assert_eq!(
+            ticks_for_duration(Duration::from_nanos(1), Duration::from_secs(1)),
+            1
+        );
+        assert_eq!(
+            ticks_for_duration(Duration::ZERO, Duration::from_secs(1)),
+            1
+        );
+    }
+
+    #[test]
+    fn ticks_for_duration_saturates_on_overflow() {
+        assert_eq!(
+            ticks_for_duration(Duration::MAX, Duration::from_nanos(1)),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn owned_engine_for_reuses_engine_for_same_recipe() {
+        let recipe = EngineRecipe {
+            wasmtime_cache: false,
+            async_support: false,
+            tick: Duration::from_millis(123),
+        };
+
+        let first = owned_engine_for(recipe).expect("cannot create engine");
+        let second = owned_engine_for(recipe).expect("cannot create engine");
+
+        assert!(wasmtime::Engine::same(&first, &second));
+    }
+
+    #[test]
+    fn owned_engine_for_creates_distinct_engines_for_different_recipes() {
+        let first = owned_engine_for(EngineRecipe {
+            wasmtime_cache: false,
+            async_support: false,
+            tick: Duration::from_millis(321),
+        })
+        .expect("cannot create engine");
+        let second = owned_engine_for(EngineRecipe {
+            wasmtime_cache: false,
+            async_support: false,
+            tick: Duration::from_millis(322),
+        })
+        .expect("cannot create engine");
+
+        assert!(!wasmtime::Engine::same(&first, &second));
+    }
+
+    #[test]
+    fn epoch_ticker_for_rejects_mismatched_tick_for_same_engine() {
+        let engine = owned_engine_for(EngineRecipe {
+            wasmtime_cache: false,
+            async_support: false,
+            tick: Duration::from_millis(500),
+        })
+        .expect("cannot create engine");
+
+        let _ticker = epoch_ticker_for(&engine, Duration::from_millis(500))
+            .expect("cannot create epoch ticker");
+
+        assert!(epoch_ticker_for(&engine, Duration::from_millis(600)).is_err());
+    }
+
+    #[test]
+    fn validate_user_input_rejects_epoch_deadlines_with_fuel_deadlines() {
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(&[])
+            .enable_epoch_interruptions(1, 1)
+            .enable_fuel_metering(1, 1);
+
+        assert!(builder.validate_user_input().is_err());
+    }
+
+    #[test]
+    fn validate_user_input_rejects_epoch_tick_durations_with_explicit_engine() {
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(&[])
+            .engine(wasmtime::Engine::default())
+            .enable_epoch_interruptions_with_durations(
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+                Duration::from_millis(100),
+            );
+
+        assert!(builder.validate_user_input().is_err());
+    }
+
+    #[test]
+    fn validate_user_input_rejects_epoch_deadlines_with_epoch_tick_durations() {
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(&[])
+            .enable_epoch_interruptions(1, 1)
+            .enable_epoch_interruptions_with_durations(
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+                Duration::from_millis(100),
+            );
+
+        assert!(builder.validate_user_input().is_err());
+    }
+
+    #[test]
+    fn validate_user_input_rejects_fuel_deadlines_with_epoch_tick_durations() {
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(&[])
+            .enable_fuel_metering(1, 1)
+            .enable_epoch_interruptions_with_durations(
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+                Duration::from_millis(100),
+            );
+
+        assert!(builder.validate_user_input().is_err());
+    }
+
+    #[test]
+    fn validate_user_input_rejects_zero_tick_for_epoch_tick_durations() {
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(&[])
+            .enable_epoch_interruptions_with_durations(
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+                Duration::ZERO,
+            );
+
+        assert!(builder.validate_user_input().is_err());
+    }
+
+    #[test]
+    fn validate_user_input_rejects_tick_greater_than_a_deadline() {
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(&[])
+            .enable_epoch_interruptions_with_durations(
+                Duration::from_millis(100),
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+            );
+
+        assert!(builder.validate_user_input().is_err());
+    }
+
     #[test]
     fn wapc_policy_is_removed_from_registry_when_the_evaluator_is_dropped() {
         // we need a real waPC policy, we don't care about the contents yet
@@ -373,4 +1281,196 @@ mod tests {
         drop(evaluator);
         assert!(!is_wapc_instance_registered(wapc_id));
     }
+
+    #[test]
+    fn precompiled_artifact_cache_key_is_stable_for_identical_input() {
+        let wasm_bytes = b"some wasm bytes";
+        let epoch_deadlines = Some(EpochDeadlines {
+            wapc_init: 1,
+            wapc_func: 1,
+        });
+
+        assert_eq!(
+            precompiled_artifact_cache_key(wasm_bytes, epoch_deadlines, None, false),
+            precompiled_artifact_cache_key(wasm_bytes, epoch_deadlines, None, false)
+        );
+    }
+
+    #[test]
+    fn precompiled_artifact_cache_key_differs_on_epoch_deadlines() {
+        let wasm_bytes = b"some wasm bytes";
+        let epoch_deadlines = Some(EpochDeadlines {
+            wapc_init: 1,
+            wapc_func: 1,
+        });
+
+        assert_ne!(
+            precompiled_artifact_cache_key(wasm_bytes, None, None, false),
+            precompiled_artifact_cache_key(wasm_bytes, epoch_deadlines, None, false)
+        );
+    }
+
+    #[test]
+    fn precompiled_artifact_cache_key_differs_on_fuel_deadlines() {
+        let wasm_bytes = b"some wasm bytes";
+        let fuel_deadlines = Some(FuelDeadlines {
+            wapc_init: 1,
+            wapc_func: 1,
+        });
+
+        assert_ne!(
+            precompiled_artifact_cache_key(wasm_bytes, None, None, false),
+            precompiled_artifact_cache_key(wasm_bytes, None, fuel_deadlines, false)
+        );
+    }
+
+    #[test]
+    fn precompiled_artifact_cache_key_differs_on_async_support() {
+        let wasm_bytes = b"some wasm bytes";
+
+        assert_ne!(
+            precompiled_artifact_cache_key(wasm_bytes, None, None, false),
+            precompiled_artifact_cache_key(wasm_bytes, None, None, true)
+        );
+    }
+
+    #[test]
+    fn precompiled_artifact_cache_key_differs_on_wasm_bytes() {
+        assert_ne!(
+            precompiled_artifact_cache_key(b"first", None, None, false),
+            precompiled_artifact_cache_key(b"second", None, None, false)
+        );
+    }
+
+    fn unique_cache_dir(test_name: &str) -> PathBuf {
+        static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+        std::env::temp_dir().join(format!(
+            "policy-evaluator-builder-test-{}-{}-{}",
+            test_name,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    #[test]
+    fn load_or_compile_module_compiles_and_persists_on_cache_miss() {
+        let cache_dir = unique_cache_dir("cache-miss");
+        let engine = wasmtime::Engine::default();
+        let wat = include_bytes!("../test_data/endless_wasm/wapc_endless_loop.wat");
+
+        load_or_compile_module(&engine, &cache_dir, wat, None, None, false)
+            .expect("cannot compile module");
+
+        let cache_path = cache_dir.join(format!(
+            "{}.cwasm",
+            precompiled_artifact_cache_key(wat, None, None, false)
+        ));
+        assert!(cache_path.exists(), "compiled artifact was not persisted");
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn load_or_compile_module_reuses_cached_artifact() {
+        let cache_dir = unique_cache_dir("cache-hit");
+        let engine = wasmtime::Engine::default();
+        let wat = include_bytes!("../test_data/endless_wasm/wapc_endless_loop.wat");
+
+        load_or_compile_module(&engine, &cache_dir, wat, None, None, false)
+            .expect("cannot compile module");
+        let cache_path = cache_dir.join(format!(
+            "{}.cwasm",
+            precompiled_artifact_cache_key(wat, None, None, false)
+        ));
+        let first_write = fs::metadata(&cache_path)
+            .expect("cache entry missing")
+            .modified()
+            .expect("no mtime");
+
+        // a second call against the same cache dir must hit the cache, not
+        // recompile and overwrite the artifact
+        load_or_compile_module(&engine, &cache_dir, wat, None, None, false)
+            .expect("cannot load cached module");
+        let second_write = fs::metadata(&cache_path)
+            .expect("cache entry missing")
+            .modified()
+            .expect("no mtime");
+        assert_eq!(first_write, second_write);
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn load_or_compile_module_falls_back_to_compiling_on_corrupt_cache_entry() {
+        let cache_dir = unique_cache_dir("corrupt-cache");
+        let engine = wasmtime::Engine::default();
+        let wat = include_bytes!("../test_data/endless_wasm/wapc_endless_loop.wat");
+
+        fs::create_dir_all(&cache_dir).expect("cannot create cache dir");
+        let cache_path = cache_dir.join(format!(
+            "{}.cwasm",
+            precompiled_artifact_cache_key(wat, None, None, false)
+        ));
+        fs::write(&cache_path, b"not a real cwasm artifact").expect("cannot write corrupt entry");
+
+        // a corrupt entry must be treated as a miss, not propagate a deserialize error
+        load_or_compile_module(&engine, &cache_dir, wat, None, None, false)
+            .expect("corrupt cache entry should fall back to compiling");
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn build_from_prebuilt_rejects_epoch_tick_durations() {
+        let wat = include_bytes!("../test_data/endless_wasm/wapc_endless_loop.wat");
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(wat);
+
+        let prebuilt = builder.prebuilt().expect("cannot build prebuilt policy");
+
+        // unlike `self.epoch_deadlines`, which `instantiate` does honor,
+        // duration-based epoch interruptions need a ticker spawned against a
+        // builder-owned engine, and there's no such engine left by the time
+        // a prebuilt policy is only being instantiated
+        let builder = builder.enable_epoch_interruptions_with_durations(
+            Duration::from_secs(1),
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+        );
+
+        assert!(builder.build_from_prebuilt(&prebuilt).is_err());
+    }
+
+    #[test]
+    fn build_from_prebuilt_rejects_epoch_interruption_enabled_only_on_the_instantiating_builder() {
+        let wat = include_bytes!("../test_data/endless_wasm/wapc_endless_loop.wat");
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(wat);
+
+        // the engine behind `prebuilt` never had `epoch_interruption` enabled
+        let prebuilt = builder.prebuilt().expect("cannot build prebuilt policy");
+
+        // asking `instantiate` to set an epoch deadline on that engine would
+        // silently have no effect, so this must be rejected instead
+        let builder = builder.enable_epoch_interruptions(1, 1);
+
+        assert!(builder.build_from_prebuilt(&prebuilt).is_err());
+    }
+
+    #[test]
+    fn build_from_prebuilt_accepts_matching_epoch_interruption_setting() {
+        let wat = include_bytes!("../test_data/endless_wasm/wapc_endless_loop.wat");
+        let builder = PolicyEvaluatorBuilder::new("test".to_string(), 0)
+            .execution_mode(PolicyExecutionMode::KubewardenWapc)
+            .policy_contents(wat)
+            .enable_epoch_interruptions(1, 1);
+
+        let prebuilt = builder.prebuilt().expect("cannot build prebuilt policy");
+
+        builder
+            .build_from_prebuilt(&prebuilt)
+            .expect("epoch interruption enabled identically on both sides should be accepted");
+    }
 }